@@ -1,5 +1,5 @@
-use chrono::{DateTime, Month};
-use rrule::{RRule, Unvalidated, Tz};
+use chrono::{DateTime, Month, NaiveDateTime, TimeZone};
+use rrule::{RRule, RRuleSet, Unvalidated, Tz};
 use rustler::{Encoder, Decoder, Env, Term, NifResult, NifStruct};
 use std::fmt;
 
@@ -31,6 +31,14 @@ enum MaybeDateTime {
     Some(DateTime<Tz>)
 }
 
+#[cfg(feature = "by-easter")]
+#[derive(Debug, Clone, Default)]
+enum MaybeByEaster {
+    #[default]
+    None,
+    Some(i16)
+}
+
 
 /// Encoding and decoding implementations for custom types to allow conversion between
 /// Elixir and Rust data structures. These implementations allow Rustler to automatically 
@@ -55,13 +63,61 @@ impl<'a> Decoder<'a> for MaybeDateTime {
     fn decode(term: Term<'a>) -> NifResult<Self> {
         if rustler::types::atom::nil() == term {
             return Ok(MaybeDateTime::None);
-        } else if let Ok(dt) = term.decode::<String>() {
-            return Ok(MaybeDateTime::Some(DateTime::parse_from_rfc3339(&dt).unwrap().with_timezone(&Tz::UTC)));
+        }
+        DateTimeArg::decode(term).map(|DateTimeArg(dt)| MaybeDateTime::Some(dt))
+    }
+}
+
+/// A datetime argument accepted from Elixir, either as an RFC 3339 string carrying its own
+/// offset or as a `{iso8601_string, "America/New_York"}` tuple naming an IANA zone.
+///
+/// Threading this through the API (instead of always forcing `Tz::UTC`) lets `UNTIL`,
+/// `DTSTART` and generated occurrences given in the tuple form come back in the named zone.
+/// An RFC 3339 string is converted to UTC (see `parse_offset_datetime`).
+#[derive(Debug, Clone)]
+struct DateTimeArg(DateTime<Tz>);
+
+impl<'a> Decoder<'a> for DateTimeArg {
+    fn decode(term: Term<'a>) -> NifResult<Self> {
+        if let Ok(dt) = term.decode::<String>() {
+            return parse_offset_datetime(&dt).map(DateTimeArg);
+        } else if let Ok((dt, tz)) = term.decode::<(String, String)>() {
+            return parse_zoned_datetime(&dt, &tz).map(DateTimeArg);
         }
         Err(rustler::Error::BadArg)
     }
 }
 
+/// Parses an RFC 3339 datetime string and converts it to UTC: the instant is kept, the
+/// offset it carries is not, since `chrono_tz::Tz` has no fixed-offset variant of its own
+fn parse_offset_datetime(dt: &str) -> NifResult<DateTime<Tz>> {
+    match DateTime::parse_from_rfc3339(dt) {
+        Ok(dt) => Ok(dt.with_timezone(&Tz::UTC)),
+        Err(_) => Err(bad_datetime(dt))
+    }
+}
+
+/// Parses a naive ISO 8601 datetime string and attaches it to a named IANA zone
+fn parse_zoned_datetime(dt: &str, tz_name: &str) -> NifResult<DateTime<Tz>> {
+    let tz: Tz = tz_name.parse::<chrono_tz::Tz>().map_err(|_| bad_timezone(tz_name))?.into();
+
+    let naive = NaiveDateTime::parse_from_str(dt, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(dt, "%Y-%m-%dT%H:%M:%S%.f"))
+        .map_err(|_| bad_datetime(dt))?;
+
+    tz.from_local_datetime(&naive).single().ok_or_else(|| bad_datetime(dt))
+}
+
+fn bad_datetime(dt: &str) -> rustler::Error {
+    let error_message = format!("Invalid datetime: {}", dt);
+    rustler::Error::Term(Box::new(error_message))
+}
+
+fn bad_timezone(tz_name: &str) -> rustler::Error {
+    let error_message = format!("Invalid timezone: {}", tz_name);
+    rustler::Error::Term(Box::new(error_message))
+}
+
 impl Encoder for MaybeCount {
     fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
         match self {
@@ -82,6 +138,38 @@ impl<'a> Decoder<'a> for MaybeCount {
     }
 }
 
+#[cfg(feature = "by-easter")]
+impl Encoder for MaybeByEaster {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        match self {
+            MaybeByEaster::None => rustler::types::atom::nil().to_term(env),
+            MaybeByEaster::Some(n) => n.encode(env)
+        }
+    }
+}
+
+#[cfg(feature = "by-easter")]
+impl<'a> Decoder<'a> for MaybeByEaster {
+    fn decode(term: Term<'a>) -> NifResult<Self> {
+        if rustler::types::atom::nil() == term {
+            return Ok(MaybeByEaster::None);
+        } else if let Ok(n) = term.decode::<i16>() {
+            return Ok(MaybeByEaster::Some(n));
+        }
+        Err(rustler::Error::BadArg)
+    }
+}
+
+#[cfg(feature = "by-easter")]
+impl std::fmt::Display for MaybeByEaster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaybeByEaster::None => write!(f, "None"),
+            MaybeByEaster::Some(n) => write!(f, "Some({})", n)
+        }
+    }
+}
+
 impl std::fmt::Display for MaybeCount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -141,6 +229,7 @@ struct Properties {
     interval: u16,
     count: MaybeCount,
     until: MaybeDateTime,
+    dt_start: MaybeDateTime,
     week_start: String,
     by_set_pos: Vec<i32>,
     by_month: Vec<u8>,
@@ -150,7 +239,9 @@ struct Properties {
     by_weekday: Vec<ExternalNWeekday>,
     by_hour: Vec<u8>,
     by_minute: Vec<u8>,
-    by_second: Vec<u8>
+    by_second: Vec<u8>,
+    #[cfg(feature = "by-easter")]
+    by_easter: MaybeByEaster
 }
 
 fn to_external_n_weekday(n_weekday: &rrule::NWeekday) -> ExternalNWeekday {
@@ -176,11 +267,14 @@ fn to_maybe_datetime(dt: Option<DateTime<Tz>>) -> MaybeDateTime {
 
 impl std::fmt::Display for Properties {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Properties {{ freq: {}, interval: {}, count: {}, until: {:?}, week_start: {}, by_set_pos: {:?}, by_month: {:?}, by_month_day: {:?}, by_year_day: {:?}, by_week_no: {:?}, by_weekday: {:?}, by_hour: {:?}, by_minute: {:?}, by_second: {:?} }}",
-            self.freq, self.interval, self.count, self.until, self.week_start, 
+        write!(f, "Properties {{ freq: {}, interval: {}, count: {}, until: {:?}, dt_start: {:?}, week_start: {}, by_set_pos: {:?}, by_month: {:?}, by_month_day: {:?}, by_year_day: {:?}, by_week_no: {:?}, by_weekday: {:?}, by_hour: {:?}, by_minute: {:?}, by_second: {:?}",
+            self.freq, self.interval, self.count, self.until, self.dt_start, self.week_start,
             self.by_set_pos, self.by_month, self.by_month_day,
             self.by_year_day, self.by_week_no, self.by_weekday, self.by_hour,
-            self.by_minute, self.by_second)
+            self.by_minute, self.by_second)?;
+        #[cfg(feature = "by-easter")]
+        write!(f, ", by_easter: {}", self.by_easter)?;
+        write!(f, " }}")
     }
 }
 
@@ -209,28 +303,12 @@ impl std::fmt::Display for Properties {
 /// // Returns Ok(Properties with freq="Weekly", interval=1, by_weekday=[String("MO"), String("WE"), String("FR")], etc.)
 /// ``` 
 fn string_to_rrule(rrule_string: String) -> NifResult<Properties> {
+    if rrule_string.contains("DTSTART") {
+        return string_with_dt_start_to_rrule(rrule_string);
+    }
+
     match rrule_string.parse::<RRule<Unvalidated>>() {
-        Ok(rrule) => Ok(Properties {
-            freq: format!("{:?}", rrule.get_freq()),
-            interval: rrule.get_interval().clone(),
-            count: to_maybe_count(rrule.get_count()),
-            until: to_maybe_datetime(rrule.get_until().copied()),
-            week_start: format!("{:?}", rrule.get_week_start()),
-            by_set_pos: rrule.get_by_set_pos().to_vec(),
-            by_month: rrule.get_by_month().to_vec(),
-            by_month_day: rrule.get_by_month_day().to_vec(),
-            by_year_day: rrule.get_by_year_day().to_vec(),
-            by_week_no: rrule.get_by_week_no().to_vec(),
-            by_weekday: rrule
-                .get_by_weekday()
-                .to_vec()
-                .iter()
-                .map(|n_weekday| to_external_n_weekday(n_weekday))
-                .collect(),
-            by_hour: rrule.get_by_hour().to_vec(),
-            by_minute: rrule.get_by_minute().to_vec(),
-            by_second: rrule.get_by_second().to_vec()
-        }),
+        Ok(rrule) => Ok(rrule_to_properties(&rrule)),
         Err(e) => {
             let error_message = format!("Error parsing rrule: {:?}", e);
             Err(rustler::Error::Term(Box::new(error_message)))
@@ -238,6 +316,74 @@ fn string_to_rrule(rrule_string: String) -> NifResult<Properties> {
     }
 }
 
+/// Parses a combined `DTSTART:...\nRRULE:...` block
+///
+/// `RRule<Unvalidated>` has no notion of `DTSTART` at all — only `RRuleSet` carries a start
+/// date. So when the input names one, we go through `RRuleSet` instead and lift its first
+/// rule plus start date back into a flat `Properties`; any further RRULE, RDATE or EXDATE
+/// lines are not kept.
+fn string_with_dt_start_to_rrule(rrule_string: String) -> NifResult<Properties> {
+    match rrule_string.parse::<RRuleSet>() {
+        Ok(rrule_set) => match rrule_set.get_rrule().first() {
+            Some(rrule) => {
+                let mut properties = rrule_to_properties(rrule);
+                properties.dt_start = to_maybe_datetime(Some(*rrule_set.get_dt_start()));
+                Ok(properties)
+            }
+            None => {
+                let error_message = "Error parsing rrule: no RRULE found alongside DTSTART".to_string();
+                Err(rustler::Error::Term(Box::new(error_message)))
+            }
+        },
+        Err(e) => {
+            let error_message = format!("Error parsing rrule: {:?}", e);
+            Err(rustler::Error::Term(Box::new(error_message)))
+        }
+    }
+}
+
+/// Converts any `RRule<S>` (validated or not) into the Properties struct sent to Elixir
+///
+/// Shared by `string_to_rrule`/`string_with_dt_start_to_rrule` and `parse_rule_set`, which
+/// all need to turn an `rrule`-crate rule back into the flat representation the Elixir side
+/// understands. `RRule<S>` itself has no start date, so `dt_start` is left `None` here;
+/// `string_with_dt_start_to_rrule` fills it in, while `parse_rule_set` keeps the start date
+/// on the `RuleSet` and leaves each rule's `dt_start` as `None`.
+fn rrule_to_properties<S>(rrule: &RRule<S>) -> Properties {
+    Properties {
+        freq: format!("{:?}", rrule.get_freq()),
+        interval: rrule.get_interval().clone(),
+        count: to_maybe_count(rrule.get_count()),
+        until: to_maybe_datetime(rrule.get_until().copied()),
+        dt_start: MaybeDateTime::None,
+        week_start: format!("{:?}", rrule.get_week_start()),
+        by_set_pos: rrule.get_by_set_pos().to_vec(),
+        by_month: rrule.get_by_month().to_vec(),
+        by_month_day: rrule.get_by_month_day().to_vec(),
+        by_year_day: rrule.get_by_year_day().to_vec(),
+        by_week_no: rrule.get_by_week_no().to_vec(),
+        by_weekday: rrule
+            .get_by_weekday()
+            .to_vec()
+            .iter()
+            .map(|n_weekday| to_external_n_weekday(n_weekday))
+            .collect(),
+        by_hour: rrule.get_by_hour().to_vec(),
+        by_minute: rrule.get_by_minute().to_vec(),
+        by_second: rrule.get_by_second().to_vec(),
+        #[cfg(feature = "by-easter")]
+        by_easter: to_maybe_by_easter(rrule.get_by_easter().copied())
+    }
+}
+
+#[cfg(feature = "by-easter")]
+fn to_maybe_by_easter(by_easter: Option<i16>) -> MaybeByEaster {
+    match by_easter {
+        Some(n) => MaybeByEaster::Some(n),
+        None => MaybeByEaster::None
+    }
+}
+
 #[rustler::nif]
 /// Converts a Properties object back into an RFC 5545 RRULE string
 ///
@@ -277,13 +423,28 @@ fn string_to_rrule(rrule_string: String) -> NifResult<Properties> {
 /// // Returns Ok("FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,WE,FR")
 /// ```
 fn rrule_to_string(p: Properties) -> NifResult<String>  {
+    let dt_start = p.dt_start.clone();
     match properties_to_rrule(p) {
-        Ok(rrule) => return Ok(format!("{}", rrule)),
+        Ok(rrule) => return Ok(format!("{}{}", format_dt_start(&dt_start), rrule)),
         Err(e) => {
             let error_message = format!("Error converting properties to rrule: {:?}", e);
             return Err(rustler::Error::Term(Box::new(error_message)));
         }
-    };    
+    };
+}
+
+/// Formats the `DTSTART:...` line to prefix a serialized RRULE, when a start date is present
+///
+/// RFC 5545 represents DTSTART as its own line ahead of RRULE, so a lossless round trip
+/// through `string_to_rrule`/`rrule_to_string` needs to emit it the same way.
+fn format_dt_start(dt_start: &MaybeDateTime) -> String {
+    match dt_start {
+        MaybeDateTime::Some(dt) if dt.timezone() == Tz::UTC =>
+            format!("DTSTART:{}\n", dt.format("%Y%m%dT%H%M%SZ")),
+        MaybeDateTime::Some(dt) =>
+            format!("DTSTART;TZID={}:{}\n", dt.timezone().name(), dt.format("%Y%m%dT%H%M%S")),
+        MaybeDateTime::None => String::new()
+    }
 }
 
 #[rustler::nif]
@@ -325,14 +486,8 @@ fn rrule_to_string(p: Properties) -> NifResult<String>  {
 /// let result = validate_rrule(env, props, "2023-02-01T00:00:00Z".to_string());
 /// // Returns Err with message about February not having 31 days
 /// ```
-fn validate_rrule(env: Env, p: Properties, dt_start: String) -> NifResult<Term> {
-    let dt_start = match DateTime::parse_from_rfc3339(&dt_start) {
-        Ok(dt) => dt.with_timezone(&Tz::UTC),
-        Err(_) => {
-            let error_message = format!("Invalid datetime: {}", dt_start);
-            return Err(rustler::Error::Term(Box::new(error_message)));
-        }
-    };
+fn validate_rrule(env: Env, p: Properties, dt_start: DateTimeArg) -> NifResult<Term> {
+    let dt_start = dt_start.0;
 
     let rrule = match properties_to_rrule(p) {
         Ok(rrule) => rrule,
@@ -478,11 +633,207 @@ fn properties_to_rrule(p: Properties) -> Result<RRule<Unvalidated>, rustler::Err
         rrule = rrule.until(dt);
     }
 
+    #[cfg(feature = "by-easter")]
+    if let MaybeByEaster::Some(n) = p.by_easter {
+        rrule = rrule.by_easter(n);
+    }
+
     return Ok(rrule);
 }
 
+/// Builds a validated, iterable `RRuleSet` from a single rule's Properties and start date
+fn build_rrule_set(p: Properties, dt_start: DateTime<Tz>) -> NifResult<RRuleSet> {
+    let rrule = properties_to_rrule(p)?;
+    rrule.build(dt_start).map_err(|e| {
+        let error_message = format!("Error building rrule: {:?}", e);
+        rustler::Error::Term(Box::new(error_message))
+    })
+}
+
+fn to_rfc3339_vec(dates: &[DateTime<Tz>]) -> Vec<String> {
+    dates.iter().map(|dt| dt.to_rfc3339()).collect()
+}
+
+/// Drops occurrences exactly at `boundary` when `inclusive` is false
+///
+/// `RRuleSet::after`/`RRuleSet::before` themselves always treat the bound as inclusive, so
+/// the boundary NIFs below lean on those builder methods (which iterate lazily and stop once
+/// past the bound, instead of generating every occurrence since `dt_start` and filtering) and
+/// only need to trim the exact boundary element back out when the caller asked for an
+/// exclusive bound.
+fn trim_boundary(dates: Vec<DateTime<Tz>>, boundary: DateTime<Tz>, inclusive: bool) -> Vec<DateTime<Tz>> {
+    if inclusive {
+        dates
+    } else {
+        dates.into_iter().filter(|dt| *dt != boundary).collect()
+    }
+}
+
+/// RuleSet maps directly to the Elixir struct in the ExDateUtil.RruleSet module.
+///
+/// Unlike Properties, which holds a single RRULE, a RuleSet composes a start date with
+/// any number of RRULEs/EXRULEs and RDATEs/EXDATEs, following RFC 5545's `RRuleSet` model:
+/// occurrences are the union of the RRULEs and RDATEs, minus anything matched by an
+/// EXRULE or EXDATE. The `exrules` field exists only when the crate is built with the
+/// `exrule` feature.
+#[derive(Debug, NifStruct, Default)]
+#[module = "ExDateUtil.RruleSet"]
+struct RuleSet {
+    dt_start: String,
+    rrules: Vec<Properties>,
+    #[cfg(feature = "exrule")]
+    exrules: Vec<Properties>,
+    rdates: Vec<String>,
+    exdates: Vec<String>
+}
+
+/// Converts a RuleSet into the internal, iterable `RRuleSet` used by the rrule crate
+fn rule_set_to_rrule_set(rs: RuleSet) -> NifResult<RRuleSet> {
+    let dt_start = parse_offset_datetime(&rs.dt_start)?;
+    let mut rrule_set = RRuleSet::new(dt_start);
+
+    for p in rs.rrules {
+        let rrule = properties_to_rrule(p)?.validate(dt_start).map_err(|e| {
+            let error_message = format!("Error validating rrule: {:?}", e);
+            rustler::Error::Term(Box::new(error_message))
+        })?;
+        rrule_set = rrule_set.rrule(rrule);
+    }
+
+    #[cfg(feature = "exrule")]
+    for p in rs.exrules {
+        let exrule = properties_to_rrule(p)?.validate(dt_start).map_err(|e| {
+            let error_message = format!("Error validating exrule: {:?}", e);
+            rustler::Error::Term(Box::new(error_message))
+        })?;
+        rrule_set = rrule_set.exrule(exrule);
+    }
+
+    for d in rs.rdates {
+        rrule_set = rrule_set.rdate(parse_offset_datetime(&d)?);
+    }
+
+    for d in rs.exdates {
+        rrule_set = rrule_set.exdate(parse_offset_datetime(&d)?);
+    }
+
+    Ok(rrule_set)
+}
+
+/// Converts an internal `RRuleSet` back into the RuleSet struct sent to Elixir
+fn rrule_set_to_rule_set(rrule_set: RRuleSet) -> RuleSet {
+    RuleSet {
+        dt_start: rrule_set.get_dt_start().to_rfc3339(),
+        rrules: rrule_set.get_rrule().iter().map(rrule_to_properties).collect(),
+        #[cfg(feature = "exrule")]
+        exrules: rrule_set.get_exrule().iter().map(rrule_to_properties).collect(),
+        rdates: rrule_set.get_rdate().iter().map(|dt| dt.to_rfc3339()).collect(),
+        exdates: rrule_set.get_exdate().iter().map(|dt| dt.to_rfc3339()).collect()
+    }
+}
+
+#[rustler::nif]
+/// Parses a full iCalendar recurrence block (DTSTART/RRULE/EXRULE/RDATE/EXDATE) into a RuleSet
+fn parse_rule_set(ical: String) -> NifResult<RuleSet> {
+    match ical.parse::<RRuleSet>() {
+        Ok(rrule_set) => Ok(rrule_set_to_rule_set(rrule_set)),
+        Err(e) => {
+            let error_message = format!("Error parsing rrule set: {:?}", e);
+            Err(rustler::Error::Term(Box::new(error_message)))
+        }
+    }
+}
+
+#[rustler::nif]
+/// Converts a RuleSet back into a full iCalendar recurrence block
+fn rule_set_to_string(rs: RuleSet) -> NifResult<String> {
+    let rrule_set = rule_set_to_rrule_set(rs)?;
+    Ok(format!("{}", rrule_set))
+}
+
+#[rustler::nif]
+/// Generates every occurrence of a RuleSet, up to `limit` results
+fn all_rule_set_occurrences(rs: RuleSet, limit: u16) -> NifResult<(Vec<String>, bool)> {
+    let rrule_set = rule_set_to_rrule_set(rs)?;
+    let result = rrule_set.all(limit);
+    Ok((to_rfc3339_vec(&result.dates), result.limited))
+}
 
+#[rustler::nif]
+/// Generates the occurrences of a RuleSet that fall between `after` and `before`
+fn rule_set_occurrences_between(rs: RuleSet, after: DateTimeArg, before: DateTimeArg, inclusive: bool) -> NifResult<Vec<String>> {
+    let rrule_set = rule_set_to_rrule_set(rs)?;
+    let dates = rrule_set.after(after.0).before(before.0).all(u16::MAX).dates;
+    let dates = trim_boundary(dates, after.0, inclusive);
+    let dates = trim_boundary(dates, before.0, inclusive);
+    Ok(to_rfc3339_vec(&dates))
+}
 
+#[rustler::nif]
+/// Finds the last occurrence of a RuleSet that falls before `before`
+fn rule_set_occurrence_before(rs: RuleSet, before: DateTimeArg, inclusive: bool) -> NifResult<MaybeDateTime> {
+    let rrule_set = rule_set_to_rrule_set(rs)?;
+    let dates = rrule_set.before(before.0).all(u16::MAX).dates;
+    let dates = trim_boundary(dates, before.0, inclusive);
+    Ok(to_maybe_datetime(dates.into_iter().last()))
+}
+
+#[rustler::nif]
+/// Finds the first occurrence of a RuleSet that falls after `after`
+fn rule_set_occurrence_after(rs: RuleSet, after: DateTimeArg, inclusive: bool) -> NifResult<MaybeDateTime> {
+    let rrule_set = rule_set_to_rrule_set(rs)?;
+    let dates = rrule_set.after(after.0).all(u16::MAX).dates;
+    let dates = trim_boundary(dates, after.0, inclusive);
+    Ok(to_maybe_datetime(dates.into_iter().next()))
+}
+
+#[rustler::nif]
+/// Generates every occurrence of a recurrence rule, up to `limit` results
+///
+/// Unbounded rules (no COUNT/UNTIL) can iterate forever, so callers must supply a `limit`.
+/// Returns the occurrences alongside a boolean indicating whether `limit` was hit before
+/// the rule was exhausted, mirroring `RRuleSet::all`'s semantics.
+fn all_occurrences(p: Properties, dt_start: DateTimeArg, limit: u16) -> NifResult<(Vec<String>, bool)> {
+    let rrule_set = build_rrule_set(p, dt_start.0)?;
+    let result = rrule_set.all(limit);
+    Ok((to_rfc3339_vec(&result.dates), result.limited))
+}
+
+#[rustler::nif]
+/// Generates the occurrences of a recurrence rule that fall between `after` and `before`, up
+/// to `limit` results
+///
+/// Like `all_occurrences`, the window can run arbitrarily far past `dt_start` for an unbounded
+/// rule, so this leans on `RRuleSet::after`/`before` (which iterate lazily and stop once past
+/// `before`, instead of generating every occurrence since `dt_start` and filtering) and
+/// surfaces whether `limit` was hit before the window was exhausted.
+fn occurrences_between(p: Properties, dt_start: DateTimeArg, after: DateTimeArg, before: DateTimeArg, inclusive: bool, limit: u16) -> NifResult<(Vec<String>, bool)> {
+    let rrule_set = build_rrule_set(p, dt_start.0)?;
+    let result = rrule_set.after(after.0).before(before.0).all(limit);
+    let dates = trim_boundary(result.dates, after.0, inclusive);
+    let dates = trim_boundary(dates, before.0, inclusive);
+    Ok((to_rfc3339_vec(&dates), result.limited))
+}
+
+#[rustler::nif]
+/// Finds the last occurrence of a recurrence rule that falls before `before`, scanning up to
+/// `limit` occurrences
+fn occurrence_before(p: Properties, dt_start: DateTimeArg, before: DateTimeArg, inclusive: bool, limit: u16) -> NifResult<(MaybeDateTime, bool)> {
+    let rrule_set = build_rrule_set(p, dt_start.0)?;
+    let result = rrule_set.before(before.0).all(limit);
+    let dates = trim_boundary(result.dates, before.0, inclusive);
+    Ok((to_maybe_datetime(dates.into_iter().last()), result.limited))
+}
+
+#[rustler::nif]
+/// Finds the first occurrence of a recurrence rule that falls after `after`, scanning up to
+/// `limit` occurrences
+fn occurrence_after(p: Properties, dt_start: DateTimeArg, after: DateTimeArg, inclusive: bool, limit: u16) -> NifResult<(MaybeDateTime, bool)> {
+    let rrule_set = build_rrule_set(p, dt_start.0)?;
+    let result = rrule_set.after(after.0).all(limit);
+    let dates = trim_boundary(result.dates, after.0, inclusive);
+    Ok((to_maybe_datetime(dates.into_iter().next()), result.limited))
+}
 
 rustler::init!(
     "Elixir.ExDateUtil.Rrule.Api"